@@ -1,22 +1,27 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::{
-    net::{TcpListener, TcpStream},
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
     path::{Path, PathBuf},
-    process::{Child, Command},
-    sync::Mutex,
+    process::{Child, Command, Stdio},
+    sync::{Arc, Mutex},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use portpicker::pick_unused_port;
 use rand::{distributions::Alphanumeric, Rng};
-use tauri::{command, Manager, State};
+// `Emitter` is a trait on the existing `tauri` dependency (Tauri 2.x, already
+// required by the `app_handle.path()` APIs used below) — not a new crate.
+use tauri::{command, Emitter, Manager, State};
 
 struct BackendState {
-    process: Mutex<Option<std::process::Child>>,
-    port: u16,
-    token: String,
+    process: Mutex<Option<Child>>,
+    port: Mutex<u16>,
+    token: Mutex<String>,
+    launcher: Box<dyn BackendLauncher>,
+    health: Mutex<HealthProbe>,
 }
 
 #[derive(serde::Serialize)]
@@ -25,12 +30,35 @@ struct BackendConfig {
     token: String,
 }
 
+/// Result of the most recent `/health` probe, surfaced to the UI.
+#[derive(Clone, serde::Serialize)]
+struct HealthProbe {
+    healthy: bool,
+    message: String,
+}
+
 #[command]
 fn get_backend_config(state: State<BackendState>) -> BackendConfig {
+    let port = *state.port.lock().unwrap();
+    let (host, port) = state.launcher.connection(port);
     BackendConfig {
-        url: format!("http://127.0.0.1:{}", state.port),
-        token: state.token.clone(),
+        url: format!("http://{}:{}", host, port),
+        token: state.token.lock().unwrap().clone(),
+    }
+}
+
+#[command]
+fn get_backend_health(state: State<BackendState>) -> HealthProbe {
+    state.health.lock().unwrap().clone()
+}
+
+/// Manually restart the backend, e.g. from a "reconnect" button in the UI.
+#[command]
+fn restart_backend(app_handle: tauri::AppHandle, state: State<BackendState>) -> Result<(), String> {
+    if let Some(mut child) = state.process.lock().unwrap().take() {
+        let _ = child.kill();
     }
+    restart_backend_once(&app_handle, &state)
 }
 
 fn main() {
@@ -43,21 +71,201 @@ fn main() {
             app.manage(backend_state);
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![get_backend_config])
+        .invoke_handler(tauri::generate_handler![
+            get_backend_config,
+            restart_backend,
+            get_backend_health
+        ])
         .build(tauri::generate_context!())
         .expect("failed to build Tauri app")
         .run(|app_handle, event| {
             if let tauri::RunEvent::ExitRequested { .. } = event {
-                // Clean up backend process
+                // Give the backend a chance to flush and close cleanly before force-killing it
                 if let Some(state) = app_handle.try_state::<BackendState>() {
                     if let Some(mut child) = state.process.lock().unwrap().take() {
-                        let _ = child.kill();
+                        let port = *state.port.lock().unwrap();
+                        let token = state.token.lock().unwrap().clone();
+                        graceful_shutdown(&mut child, state.launcher.as_ref(), port, &token);
                     }
                 }
             }
         });
 }
 
+/// Shutdown grace period: ask the backend to stop, then poll before force-killing it.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Ask the backend to stop on its own, wait up to `SHUTDOWN_GRACE` for it to exit,
+/// and only `kill()` it if it's still alive afterwards. This avoids corrupting the
+/// Python backend's open files or leaving temp state behind, mirroring the
+/// graceful-shutdown pattern used on the server side.
+fn graceful_shutdown(child: &mut Child, launcher: &dyn BackendLauncher, port: u16, token: &str) {
+    request_stop(launcher, port, token, child.id());
+
+    let deadline = Instant::now() + SHUTDOWN_GRACE;
+    while Instant::now() < deadline {
+        match child.try_wait() {
+            Ok(Some(_)) => return,
+            Ok(None) => thread::sleep(SHUTDOWN_POLL_INTERVAL),
+            Err(err) => {
+                eprintln!("[ERROR] failed to poll backend during shutdown: {}", err);
+                break;
+            }
+        }
+    }
+
+    if let Ok(None) = child.try_wait() {
+        eprintln!("[WARN] backend did not exit within grace period, killing it");
+        let _ = child.kill();
+    }
+}
+
+/// Best-effort request to stop the backend: an authenticated POST to `/shutdown`,
+/// falling back to a SIGTERM on Unix if that fails. Windows has no equivalent
+/// signal for this process (see `send_stop_signal` below), so there the grace
+/// period is really "give the POST a chance, then kill() if it didn't work".
+fn request_stop(launcher: &dyn BackendLauncher, port: u16, token: &str, pid: u32) {
+    if post_shutdown(launcher, port, token).is_err() {
+        send_stop_signal(pid);
+    }
+}
+
+/// POST `/shutdown` to `launcher.connection(port)` — not just `127.0.0.1` on the
+/// locally selected port, since `ConfiguredLauncher` may point at a remote or
+/// containerized backend — and require a 2xx response before treating it as
+/// handled. A backend with no such route (or one that ignores it) would
+/// otherwise make the connect+write succeed and the SIGTERM fallback would
+/// never fire.
+fn post_shutdown(launcher: &dyn BackendLauncher, port: u16, token: &str) -> Result<(), String> {
+    let (host, port) = launcher.connection(port);
+    let addr = (host.as_str(), port)
+        .to_socket_addrs()
+        .map_err(|err| format!("invalid address: {}", err))?
+        .next()
+        .ok_or_else(|| format!("could not resolve {}:{}", host, port))?;
+
+    let mut stream =
+        TcpStream::connect_timeout(&addr, Duration::from_millis(500)).map_err(|err| err.to_string())?;
+    stream
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .map_err(|err| err.to_string())?;
+    stream
+        .set_write_timeout(Some(Duration::from_millis(500)))
+        .map_err(|err| err.to_string())?;
+
+    let request = format!(
+        "POST /shutdown HTTP/1.1\r\nHost: {host}:{port}\r\nAuthorization: Bearer {token}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        host = host,
+        port = port,
+        token = token,
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|err| err.to_string())?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|err| err.to_string())?;
+
+    match parse_status_code(response.lines().next().unwrap_or("")) {
+        Some(200..=299) => Ok(()),
+        _ => Err(format!(
+            "shutdown request was not acknowledged: {:?}",
+            response.lines().next().unwrap_or("")
+        )),
+    }
+}
+
+/// Extract the numeric status code from an HTTP status line, e.g. `200` from
+/// `"HTTP/1.1 200 OK"`.
+fn parse_status_code(status_line: &str) -> Option<u16> {
+    status_line.split_whitespace().nth(1)?.parse().ok()
+}
+
+#[cfg(unix)]
+fn send_stop_signal(pid: u32) {
+    let _ = Command::new("kill")
+        .args(["-TERM", &pid.to_string()])
+        .status();
+}
+
+#[cfg(windows)]
+fn send_stop_signal(_pid: u32) {
+    // No-op: `GenerateConsoleCtrlEvent` only delivers CTRL_C/CTRL_BREAK (not
+    // CTRL_CLOSE) to processes attached to *our* console, and its second
+    // argument is a process-group id, not a pid — and a `windows_subsystem =
+    // "windows"` build has no console to deliver from in the first place. There
+    // is no reliable graceful-stop signal available here, so Windows relies
+    // entirely on the POST /shutdown above; `graceful_shutdown`'s grace-period
+    // timeout falls through to a forceful `kill()` if that didn't work.
+}
+
+/// Tauri event carrying one line of backend output, so the frontend can show a live console.
+#[derive(Clone, serde::Serialize)]
+struct BackendLogLine {
+    stream: &'static str,
+    line: String,
+}
+
+/// Rotate the log file once it grows past this size, keeping a single backup.
+const LOG_ROTATE_BYTES: u64 = 5 * 1024 * 1024;
+
+fn backend_log_path(app_handle: &tauri::AppHandle) -> Option<PathBuf> {
+    let log_dir = app_handle.path().app_log_dir().ok()?;
+    std::fs::create_dir_all(&log_dir).ok()?;
+    Some(log_dir.join("backend.log"))
+}
+
+/// `lock` serializes access across the stdout and stderr reader threads
+/// (see `spawn_log_reader`), since both threads share the same log file and
+/// without it `writeln!`'s multiple `write()` syscalls per line can interleave,
+/// and the size-check-then-rename rotation below can race and double-rotate.
+fn append_log_line(lock: &Mutex<()>, path: &Path, stream: &str, line: &str) {
+    let _guard = lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Ok(meta) = std::fs::metadata(path) {
+        if meta.len() > LOG_ROTATE_BYTES {
+            let _ = std::fs::rename(path, path.with_extension("log.1"));
+        }
+    }
+
+    if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "[{}] {}", stream, line);
+    }
+}
+
+/// Drain a piped stdout/stderr handle line by line, appending each line to the
+/// rotating log file and forwarding it to the frontend as a `backend://log` event.
+/// `log_lock` is shared with the sibling stdout/stderr reader so the two
+/// threads never write to or rotate the log file concurrently.
+fn spawn_log_reader<R: Read + Send + 'static>(
+    reader: R,
+    stream: &'static str,
+    app_handle: tauri::AppHandle,
+    log_path: Option<PathBuf>,
+    log_lock: Arc<Mutex<()>>,
+) {
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines() {
+            let Ok(line) = line else { break };
+
+            if let Some(path) = &log_path {
+                append_log_line(&log_lock, path, stream, &line);
+            }
+
+            let _ = app_handle.emit(
+                "backend://log",
+                BackendLogLine {
+                    stream,
+                    line,
+                },
+            );
+        }
+    });
+}
+
 fn spawn_backend(app_handle: tauri::AppHandle) -> Result<BackendState, Box<dyn std::error::Error>> {
     let token: String = rand::thread_rng()
         .sample_iter(&Alphanumeric)
@@ -69,65 +277,42 @@ fn spawn_backend(app_handle: tauri::AppHandle) -> Result<BackendState, Box<dyn s
     eprintln!("[DEBUG] Backend path: {:?}", backend_path);
     eprintln!("[DEBUG] Is directory: {}", backend_path.is_dir());
 
+    let launcher = select_launcher(&backend_path);
+
     let mut last_error: Option<String> = None;
 
     for attempt in 0..5 {
         let port = select_port(attempt);
         eprintln!("[DEBUG] Attempt #{}, using port {}", attempt + 1, port);
 
-        let mut command = if backend_path.is_dir() {
-            // Development: python module (backend_path is the backend directory)
-            let project_root = backend_path
-                .parent()
-                .ok_or("Invalid backend path structure in development mode")?;
-            let python_bin = find_venv_python(project_root).unwrap_or_else(|| "python3".into());
-            let mut cmd = Command::new(python_bin);
-            cmd.args(["-m", "backend"]);
-            eprintln!("[DEBUG] Running: python3 -m backend");
-            eprintln!("[DEBUG] Working directory: {:?}", project_root);
-            cmd.current_dir(project_root);
-            cmd
-        } else {
-            // Production: standalone executable
-            eprintln!("[DEBUG] Running standalone executable: {:?}", backend_path);
-            Command::new(&backend_path)
-        };
-
-        command.env("ECHOSMITH_PORT", port.to_string());
-        command.env("ECHOSMITH_TOKEN", &token);
-        eprintln!("[DEBUG] Port: {}, Token: {}", port, &token);
-
-        match command.spawn() {
-            Ok(mut child) => {
-                eprintln!(
-                    "[DEBUG] Backend process spawned successfully, PID: {:?}",
-                    child.id()
-                );
-                match wait_for_backend(&mut child, port) {
-                    Ok(()) => {
-                        return Ok(BackendState {
-                            process: Mutex::new(Some(child)),
-                            port,
-                            token: token.clone(),
-                        });
-                    }
-                    Err(wait_err) => {
-                        eprintln!(
-                            "[ERROR] Backend did not become ready on port {}: {}",
-                            port, wait_err
-                        );
-                        let _ = child.kill();
-                        last_error = Some(wait_err);
-                    }
+        match try_spawn_once(&app_handle, launcher.as_ref(), port, &token) {
+            Ok(mut child) => match wait_for_backend(&mut child, launcher.as_ref(), port, &token) {
+                Ok(()) => {
+                    let state = BackendState {
+                        process: Mutex::new(Some(child)),
+                        port: Mutex::new(port),
+                        token: Mutex::new(token),
+                        launcher,
+                        health: Mutex::new(HealthProbe {
+                            healthy: true,
+                            message: "ok".to_string(),
+                        }),
+                    };
+                    start_supervisor(app_handle);
+                    return Ok(state);
                 }
-            }
+                Err(wait_err) => {
+                    eprintln!(
+                        "[ERROR] Backend did not become ready on port {}: {}",
+                        port, wait_err
+                    );
+                    let _ = child.kill();
+                    last_error = Some(wait_err);
+                }
+            },
             Err(spawn_err) => {
-                let err_msg = format!(
-                    "Failed to start backend at {:?}: {}",
-                    backend_path, spawn_err
-                );
-                eprintln!("[ERROR] {}", err_msg);
-                last_error = Some(err_msg);
+                eprintln!("[ERROR] {}", spawn_err);
+                last_error = Some(spawn_err);
             }
         }
 
@@ -139,6 +324,367 @@ fn spawn_backend(app_handle: tauri::AppHandle) -> Result<BackendState, Box<dyn s
         .into())
 }
 
+/// Spawn the backend via `launcher`'s command, and wire up its stdout/stderr to
+/// the log reader threads. Does not wait for readiness; callers should follow up
+/// with `wait_for_backend`. Shared by the initial launch and the watchdog
+/// supervisor's restart path so both spawn the backend identically.
+fn try_spawn_once(
+    app_handle: &tauri::AppHandle,
+    launcher: &dyn BackendLauncher,
+    port: u16,
+    token: &str,
+) -> Result<Child, String> {
+    let mut command = launcher.build_command(port, token);
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+    eprintln!("[DEBUG] Port: {}, Token: {}", port, token);
+
+    let mut child = command
+        .spawn()
+        .map_err(|err| format!("Failed to start backend: {}", err))?;
+    eprintln!(
+        "[DEBUG] Backend process spawned successfully, PID: {:?}",
+        child.id()
+    );
+
+    let log_path = backend_log_path(app_handle);
+    let log_lock = Arc::new(Mutex::new(()));
+    if let Some(stdout) = child.stdout.take() {
+        spawn_log_reader(
+            stdout,
+            "stdout",
+            app_handle.clone(),
+            log_path.clone(),
+            log_lock.clone(),
+        );
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_log_reader(
+            stderr,
+            "stderr",
+            app_handle.clone(),
+            log_path.clone(),
+            log_lock.clone(),
+        );
+    }
+
+    Ok(child)
+}
+
+/// Builds the `Command` used to spawn the backend and probes its readiness.
+/// Implemented for the built-in dev (Python module) and packaged (standalone
+/// executable) modes, and for a user-configured launcher read from
+/// `echosmith.toml`, so people can point EchoSmith at a custom, remote, or
+/// containerized backend without recompiling.
+trait BackendLauncher: Send + Sync {
+    fn build_command(&self, port: u16, token: &str) -> Command;
+
+    /// Host and port the app should actually connect to. Defaults to the
+    /// locally spawned process on loopback; `ConfiguredLauncher` overrides this
+    /// when `echosmith.toml` points at a different host/port, since a remote or
+    /// containerized backend isn't reachable on `127.0.0.1` at the locally
+    /// selected `port`.
+    fn connection(&self, port: u16) -> (String, u16) {
+        ("127.0.0.1".to_string(), port)
+    }
+
+    fn probe_ready(&self, port: u16, token: &str) -> Result<(), String> {
+        let (host, port) = self.connection(port);
+        probe_health(&host, port, token)
+    }
+}
+
+/// Development mode: run `python -m backend` from the project root (the parent
+/// of the backend directory).
+struct PythonModuleLauncher {
+    project_root: PathBuf,
+}
+
+impl BackendLauncher for PythonModuleLauncher {
+    fn build_command(&self, port: u16, token: &str) -> Command {
+        let python_bin =
+            find_venv_python(&self.project_root).unwrap_or_else(|| "python3".into());
+        eprintln!("[DEBUG] Running: {:?} -m backend", python_bin);
+        eprintln!("[DEBUG] Working directory: {:?}", self.project_root);
+
+        let mut cmd = Command::new(python_bin);
+        cmd.args(["-m", "backend"]);
+        cmd.current_dir(&self.project_root);
+        cmd.env("ECHOSMITH_PORT", port.to_string());
+        cmd.env("ECHOSMITH_TOKEN", token);
+        cmd
+    }
+}
+
+/// Production mode: run the packaged standalone `backend` executable.
+struct ExecutableLauncher {
+    executable_path: PathBuf,
+}
+
+impl BackendLauncher for ExecutableLauncher {
+    fn build_command(&self, port: u16, token: &str) -> Command {
+        eprintln!("[DEBUG] Running standalone executable: {:?}", self.executable_path);
+
+        let mut cmd = Command::new(&self.executable_path);
+        cmd.env("ECHOSMITH_PORT", port.to_string());
+        cmd.env("ECHOSMITH_TOKEN", token);
+        cmd
+    }
+}
+
+/// Launcher read from an optional `echosmith.toml`, overriding the program,
+/// argument template, working directory, and extra environment variables used
+/// to start the backend. `host`/`port`, if set, point the app at a different
+/// address than the locally spawned process — e.g. a remote or containerized
+/// backend — rather than `127.0.0.1` on the locally selected port.
+struct ConfiguredLauncher {
+    program: PathBuf,
+    args: Vec<String>,
+    working_dir: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    host: Option<String>,
+    port: Option<u16>,
+}
+
+impl BackendLauncher for ConfiguredLauncher {
+    fn build_command(&self, port: u16, token: &str) -> Command {
+        eprintln!("[DEBUG] Running launcher from echosmith.toml: {:?}", self.program);
+
+        let mut cmd = Command::new(&self.program);
+        cmd.args(
+            self.args
+                .iter()
+                .map(|arg| arg.replace("{port}", &port.to_string()).replace("{token}", token)),
+        );
+        if let Some(working_dir) = &self.working_dir {
+            cmd.current_dir(working_dir);
+        }
+        for (key, value) in &self.env {
+            cmd.env(key, value);
+        }
+        cmd.env("ECHOSMITH_PORT", port.to_string());
+        cmd.env("ECHOSMITH_TOKEN", token);
+        cmd
+    }
+
+    fn connection(&self, port: u16) -> (String, u16) {
+        (
+            self.host.clone().unwrap_or_else(|| "127.0.0.1".to_string()),
+            self.port.unwrap_or(port),
+        )
+    }
+}
+
+#[derive(Default)]
+struct LauncherConfig {
+    program: Option<PathBuf>,
+    args: Vec<String>,
+    working_dir: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    host: Option<String>,
+    port: Option<u16>,
+}
+
+/// Parse the flat `key = "value"` shape `echosmith.toml` uses, plus an `[env]`
+/// table of string key/value pairs. Hand-rolled rather than pulling in a TOML
+/// crate for a handful of scalar/array/string-map fields, matching this file's
+/// existing dependency-light style (e.g. the hand-rolled HTTP client above).
+/// Unknown keys and sections are ignored.
+fn parse_launcher_toml(contents: &str) -> LauncherConfig {
+    let mut config = LauncherConfig::default();
+    let mut in_env = false;
+    let mut in_unknown_section = false;
+
+    for raw_line in contents.lines() {
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with('[') {
+            in_env = line == "[env]";
+            in_unknown_section = !in_env;
+            continue;
+        }
+        if in_unknown_section {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+
+        if in_env {
+            if let Some(value) = parse_toml_string(value) {
+                config.env.push((key.to_string(), value));
+            }
+            continue;
+        }
+
+        match key {
+            "program" => config.program = parse_toml_string(value).map(PathBuf::from),
+            "working_dir" => config.working_dir = parse_toml_string(value).map(PathBuf::from),
+            "host" => config.host = parse_toml_string(value),
+            "port" => config.port = value.parse().ok(),
+            "args" => config.args = parse_toml_string_array(value).unwrap_or_default(),
+            _ => {}
+        }
+    }
+
+    config
+}
+
+fn parse_toml_string(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    (raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"'))
+        .then(|| raw[1..raw.len() - 1].to_string())
+}
+
+fn parse_toml_string_array(raw: &str) -> Option<Vec<String>> {
+    let inner = raw.trim().strip_prefix('[')?.strip_suffix(']')?;
+    Some(
+        inner
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(parse_toml_string)
+            .collect(),
+    )
+}
+
+/// Look for `echosmith.toml` alongside `backend_path` and, if present and it
+/// names a `program`, build a `ConfiguredLauncher` from it.
+fn load_configured_launcher(backend_path: &Path) -> Option<ConfiguredLauncher> {
+    let config_dir = backend_path.parent()?;
+    let contents = std::fs::read_to_string(config_dir.join("echosmith.toml")).ok()?;
+    let config = parse_launcher_toml(&contents);
+
+    Some(ConfiguredLauncher {
+        program: config.program?,
+        args: config.args,
+        working_dir: config.working_dir,
+        env: config.env,
+        host: config.host,
+        port: config.port,
+    })
+}
+
+/// Pick the launcher to use: a user-supplied `echosmith.toml` override if one is
+/// found, otherwise the built-in dev/packaged launcher for `backend_path`.
+fn select_launcher(backend_path: &Path) -> Box<dyn BackendLauncher> {
+    if let Some(launcher) = load_configured_launcher(backend_path) {
+        eprintln!("[DEBUG] Using launcher from echosmith.toml");
+        return Box::new(launcher);
+    }
+
+    if backend_path.is_dir() {
+        // Development: python module (backend_path is the backend directory)
+        let project_root = backend_path.parent().unwrap_or(backend_path).to_path_buf();
+        Box::new(PythonModuleLauncher { project_root })
+    } else {
+        // Production: standalone executable
+        Box::new(ExecutableLauncher {
+            executable_path: backend_path.to_path_buf(),
+        })
+    }
+}
+
+/// How long to wait between watchdog polls of the backend process.
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Exponential backoff between restart attempts after an unexpected exit.
+const RESTART_BACKOFFS_MS: [u64; 5] = [200, 400, 800, 1600, 3200];
+
+/// Watch the backend process for unexpected exits and restart it with backoff,
+/// so a mid-session crash of the Python backend doesn't silently strand the UI.
+fn start_supervisor(app_handle: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(SUPERVISOR_POLL_INTERVAL);
+
+        let Some(state) = app_handle.try_state::<BackendState>() else {
+            break;
+        };
+
+        let exited = {
+            let mut process = state.process.lock().unwrap();
+            match process.as_mut() {
+                Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                None => false,
+            }
+        };
+
+        if !exited {
+            // Process is still running, but that doesn't mean it's healthy — probe
+            // it so `get_backend_health` reflects reality instead of a stale "ok"
+            // from the last spawn/restart.
+            let port = *state.port.lock().unwrap();
+            let token = state.token.lock().unwrap().clone();
+            *state.health.lock().unwrap() = match state.launcher.probe_ready(port, &token) {
+                Ok(()) => HealthProbe {
+                    healthy: true,
+                    message: "ok".to_string(),
+                },
+                Err(err) => HealthProbe {
+                    healthy: false,
+                    message: err,
+                },
+            };
+            continue;
+        }
+
+        eprintln!("[WARN] backend exited unexpectedly, attempting to restart");
+        state.process.lock().unwrap().take();
+
+        for (attempt, backoff_ms) in RESTART_BACKOFFS_MS.iter().enumerate() {
+            thread::sleep(Duration::from_millis(*backoff_ms));
+
+            match restart_backend_once(&app_handle, &state) {
+                Ok(()) => {
+                    eprintln!("[DEBUG] backend restarted on attempt {}", attempt + 1);
+                    let _ = app_handle.emit("backend://restarted", ());
+                    break;
+                }
+                Err(err) => {
+                    eprintln!("[ERROR] restart attempt {} failed: {}", attempt + 1, err);
+                }
+            }
+        }
+    });
+}
+
+/// Spawn a fresh backend with a regenerated token and port, and install it into
+/// `state` on success. Used by both the watchdog supervisor and `restart_backend`.
+fn restart_backend_once(app_handle: &tauri::AppHandle, state: &BackendState) -> Result<(), String> {
+    let token: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+    let port = pick_unused_port().ok_or("no free port available")?;
+
+    let mut child = try_spawn_once(app_handle, state.launcher.as_ref(), port, &token)?;
+
+    match wait_for_backend(&mut child, state.launcher.as_ref(), port, &token) {
+        Ok(()) => {
+            *state.port.lock().unwrap() = port;
+            *state.token.lock().unwrap() = token;
+            *state.process.lock().unwrap() = Some(child);
+            *state.health.lock().unwrap() = HealthProbe {
+                healthy: true,
+                message: "ok".to_string(),
+            };
+            Ok(())
+        }
+        Err(err) => {
+            *state.health.lock().unwrap() = HealthProbe {
+                healthy: false,
+                message: err.clone(),
+            };
+            let _ = child.kill();
+            Err(err)
+        }
+    }
+}
+
 fn select_port(attempt: usize) -> u16 {
     if cfg!(debug_assertions) {
         if attempt == 0 {
@@ -157,7 +703,24 @@ fn select_port(attempt: usize) -> u16 {
     }
 }
 
-fn wait_for_backend(child: &mut Child, port: u16) -> Result<(), String> {
+/// Poll the backend's `/health` endpoint until it answers 200, rather than just
+/// checking that the port accepts TCP connections. An HTTP server often accepts
+/// the socket before its routes are mounted, so a bare TCP check can report
+/// "ready" a moment before the application layer actually is.
+///
+/// We don't have a reference backend in this repo to confirm it actually
+/// mounts `/health` with Bearer auth, so a missing/incompatible route must not
+/// brick startup: if the health check never succeeds but the port accepted a
+/// TCP connection on at least one poll, fall back to treating that as ready.
+fn wait_for_backend(
+    child: &mut Child,
+    launcher: &dyn BackendLauncher,
+    port: u16,
+    token: &str,
+) -> Result<(), String> {
+    let mut last_err = String::new();
+    let mut tcp_ready = false;
+
     for _ in 0..80 {
         if let Some(status) = child
             .try_wait()
@@ -166,23 +729,85 @@ fn wait_for_backend(child: &mut Child, port: u16) -> Result<(), String> {
             return Err(format!("backend exited early with status: {}", status));
         }
 
-        match TcpStream::connect(("127.0.0.1", port)) {
-            Ok(stream) => {
-                drop(stream);
-                return Ok(());
-            }
+        match launcher.probe_ready(port, token) {
+            Ok(()) => return Ok(()),
             Err(err) => {
-                if err.kind() == std::io::ErrorKind::ConnectionRefused
-                    || err.kind() == std::io::ErrorKind::TimedOut
-                {
-                    thread::sleep(Duration::from_millis(150));
-                    continue;
+                last_err = err;
+                if probe_tcp_reachable(launcher, port) {
+                    tcp_ready = true;
                 }
-                return Err(err.to_string());
+                thread::sleep(Duration::from_millis(150));
             }
         }
     }
-    Err("backend did not open port within timeout".to_string())
+
+    if tcp_ready {
+        eprintln!(
+            "[WARN] backend never passed the /health check ({}), but its port is accepting \
+             connections — treating it as ready. Implement `GET /health` (200, `Authorization: \
+             Bearer <token>`) for accurate readiness and health reporting.",
+            last_err
+        );
+        return Ok(());
+    }
+
+    Err(format!(
+        "backend did not pass health check within timeout: {}",
+        last_err
+    ))
+}
+
+/// Bare TCP reachability check, used only as a fallback readiness signal when
+/// the `/health` contract can't be confirmed (see `wait_for_backend`).
+fn probe_tcp_reachable(launcher: &dyn BackendLauncher, port: u16) -> bool {
+    let (host, port) = launcher.connection(port);
+    (host.as_str(), port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .and_then(|addr| TcpStream::connect_timeout(&addr, Duration::from_millis(300)).ok())
+        .is_some()
+}
+
+/// Issue an authenticated `GET /health` against `host:port` and require a 200
+/// response. Retried by the caller on connection-refused, timeout, or a
+/// non-200 status. `host` is resolved via `ToSocketAddrs` rather than parsed
+/// as a literal IP, so a remote/containerized backend's hostname works too.
+fn probe_health(host: &str, port: u16, token: &str) -> Result<(), String> {
+    let addr = (host, port)
+        .to_socket_addrs()
+        .map_err(|err| format!("invalid address: {}", err))?
+        .next()
+        .ok_or_else(|| format!("could not resolve {}:{}", host, port))?;
+    let mut stream = TcpStream::connect_timeout(&addr, Duration::from_millis(300))
+        .map_err(|err| err.to_string())?;
+    stream
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .map_err(|err| err.to_string())?;
+    stream
+        .set_write_timeout(Some(Duration::from_millis(300)))
+        .map_err(|err| err.to_string())?;
+
+    let request = format!(
+        "GET /health HTTP/1.1\r\nHost: {host}:{port}\r\nAuthorization: Bearer {token}\r\nConnection: close\r\n\r\n",
+        host = host,
+        port = port,
+        token = token,
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|err| err.to_string())?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|err| err.to_string())?;
+
+    let status_line = response.lines().next().unwrap_or("");
+    match parse_status_code(status_line) {
+        Some(200) => Ok(()),
+        _ => Err(format!("unexpected health response: {:?}", status_line)),
+    }
 }
 
 fn get_backend_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {